@@ -1,7 +1,10 @@
 use std::any::Any;
 use std::ops::Bound;
 
-use nu_protocol::{CustomValue, FloatRange, FromValue, IntoValue, ShellError, Span, Type, Value};
+use nu_protocol::engine::Closure;
+use nu_protocol::{
+    CustomValue, FloatRange, FromValue, IntoValue, Record, ShellError, Span, Type, Value,
+};
 use serde::{Deserialize, Serialize};
 
 use super::color::Color;
@@ -18,8 +21,23 @@ pub struct Chart2d {
     pub label_area: [u32; 4],
     pub x_range: Option<Range>,
     pub y_range: Option<Range>,
+    pub x_scale: AxisScale,
+    pub y_scale: AxisScale,
+    pub padding: Option<f64>,
+    pub legend: Option<LegendConfig>,
+    pub x_label: Option<String>,
+    pub y_label: Option<String>,
+    pub x_desc: Option<String>,
+    pub y_desc: Option<String>,
+    pub grid: bool,
+    pub x_tick_format: Option<TickFormat>,
+    pub y_tick_format: Option<TickFormat>,
 }
 
+/// Default fraction by which auto-computed extents are padded on each
+/// unbounded side so the outermost points do not touch the chart border.
+const DEFAULT_PADDING: f64 = 0.05;
+
 impl Default for Chart2d {
     fn default() -> Self {
         Self {
@@ -32,6 +50,17 @@ impl Default for Chart2d {
             label_area: [0, 0, 35, 35],
             x_range: None,
             y_range: None,
+            x_scale: AxisScale::default(),
+            y_scale: AxisScale::default(),
+            padding: None,
+            legend: None,
+            x_label: None,
+            y_label: None,
+            x_desc: None,
+            y_desc: None,
+            grid: true,
+            x_tick_format: None,
+            y_tick_format: None,
         }
     }
 }
@@ -81,60 +110,257 @@ impl CustomValue for Chart2d {
     }
 }
 
+/// Smallest positive bound used on a logarithmic axis when every derived
+/// extent collapses to a non-positive value.
+const LOG_EPSILON: f64 = 1e-10;
+
 macro_rules! xy_range {
-    ($fn_name:ident) => {
-        pub fn $fn_name(&self) -> Option<Range> {
-            if let Some(range) = self.$fn_name {
-                return Some(range);
+    ($fn_name:ident, $scale:ident) => {
+        pub fn $fn_name(&self) -> Result<Option<Range>, ShellError> {
+            let logarithmic = self.$scale.is_logarithmic();
+            let explicit = self.$fn_name;
+
+            // A caller-supplied minimum must be positive on a log axis.
+            if logarithmic {
+                if let Some(min) = explicit.and_then(|r| r.min) {
+                    if min <= 0.0 {
+                        return Err(ShellError::GenericError {
+                            error: "invalid range for logarithmic axis".into(),
+                            msg: format!(
+                                "range minimum {min} is not positive on a {} scale",
+                                self.$scale
+                            ),
+                            span: None,
+                            help: Some("Supply a range whose minimum is greater than zero.".into()),
+                            inner: vec![],
+                        });
+                    }
+                }
             }
 
-            let first = self.series.first()?;
-            let Range { mut min, mut max } = first.$fn_name()?;
-            for Range { min: s_min, max: s_max } in self.series.iter().filter_map(|s| s.$fn_name()) {
-                if s_min < min {
-                    min = s_min
+            // Fully bounded by the caller: nothing to resolve or pad.
+            if let Some(Range { min: Some(min), max: Some(max) }) = explicit {
+                return Ok(Some(Range { min: Some(min), max: Some(max) }));
+            }
+
+            // Derive the data extents. On a log axis a series whose lower
+            // bound is not positive is skipped whole, its positive points
+            // included; no bound is clamped.
+            let mut extent: Option<(f64, f64)> = None;
+            for range in self.series.iter().filter_map(|s| s.$fn_name()) {
+                let (s_min, s_max) = match (range.min, range.max) {
+                    (Some(min), Some(max)) => (min, max),
+                    _ => continue,
+                };
+                let (s_min, s_max) = if logarithmic {
+                    // A series exposes only an aggregate min/max, so genuine
+                    // point-level filtering of non-positive values belongs in
+                    // `Series2d::x_range`/`y_range`. At this layer we can only
+                    // drop a series whose lower bound is not positive — doing
+                    // so is correct, whereas clamping it up to an epsilon would
+                    // blow the axis out by dozens of spurious decades.
+                    if s_min <= 0.0 {
+                        continue;
+                    }
+                    (s_min, s_max)
+                } else {
+                    (s_min, s_max)
+                };
+
+                extent = Some(match extent {
+                    Some((min, max)) => (min.min(s_min), max.max(s_max)),
+                    None => (s_min, s_max),
+                });
+            }
+
+            let (data_min, data_max) = match extent {
+                Some(extent) => extent,
+                // No usable data. Under a log scale, derive a missing bound
+                // from the other side when the caller supplied one, else fall
+                // back to a small positive window. Otherwise return the
+                // caller's range (possibly half-open, or none) unchanged.
+                None if logarithmic => {
+                    let (min, max) =
+                        match (explicit.and_then(|r| r.min), explicit.and_then(|r| r.max)) {
+                            (Some(min), Some(max)) => (min, max),
+                            (Some(min), None) => (min, min * 10.0),
+                            (None, Some(max)) => (max / 10.0, max),
+                            (None, None) => (LOG_EPSILON, LOG_EPSILON * 10.0),
+                        };
+                    return Ok(Some(Range { min: Some(min), max: Some(max) }));
+                }
+                None => return Ok(explicit),
+            };
+
+            // Pad the sides that were resolved from data so the outermost
+            // points do not sit flush against the chart border. On a log axis
+            // the padding is applied in log space, so it adds a fixed fraction
+            // of the spanned decades rather than a linear offset that would
+            // distort the scale (or collapse to the epsilon floor).
+            let padding = self.padding.unwrap_or(DEFAULT_PADDING);
+
+            let min = match explicit.and_then(|r| r.min) {
+                Some(min) => min,
+                None if logarithmic => {
+                    let log_span = data_max.ln() - data_min.ln();
+                    (data_min.ln() - log_span * padding).exp().max(LOG_EPSILON)
                 }
-                if s_max > max {
-                    max = s_max
+                None => data_min - (data_max - data_min) * padding,
+            };
+            let max = match explicit.and_then(|r| r.max) {
+                Some(max) => max,
+                None if logarithmic => {
+                    let log_span = data_max.ln() - data_min.ln();
+                    (data_max.ln() + log_span * padding).exp()
                 }
-            }
+                None => data_max + (data_max - data_min) * padding,
+            };
 
-            Some(Range { min, max })
+            Ok(Some(Range { min: Some(min), max: Some(max) }))
         }
     };
 }
 
 impl Chart2d {
-    xy_range!(x_range);
+    xy_range!(x_range, x_scale);
 
-    xy_range!(y_range);
+    xy_range!(y_range, y_scale);
 
     pub fn ty() -> Type {
         Type::Custom("plotters::chart-2d".to_string().into_boxed_str())
     }
 }
 
+/// How the values along an axis are mapped onto screen space.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum AxisScale {
+    /// A plain linear scale (the default).
+    #[default]
+    Linear,
+    /// A base-10 logarithmic scale.
+    Log10,
+    /// A natural (base-e) logarithmic scale.
+    Ln,
+    /// A symmetric-log scale that is linear within `[-linthresh, linthresh]`
+    /// and logarithmic beyond it, so data straddling zero stays plottable.
+    SymLog { linthresh: f64 },
+}
+
+impl AxisScale {
+    /// Whether this scale positions ticks logarithmically and therefore
+    /// requires strictly positive extents. [`AxisScale::SymLog`] is
+    /// intentionally *not* logarithmic here: it is linear within its
+    /// threshold and so accepts values at or below zero, meaning its extents
+    /// are computed exactly like a linear axis.
+    pub fn is_logarithmic(&self) -> bool {
+        matches!(self, AxisScale::Log10 | AxisScale::Ln)
+    }
+}
+
+impl IntoValue for AxisScale {
+    // Mirrors `FromValue` so a scale survives a round-trip through `Value`:
+    // the logarithmic variants emit their canonical lowercase name and
+    // `SymLog` emits the `{ type, linthresh }` record its parser accepts.
+    fn into_value(self, span: Span) -> Value {
+        match self {
+            AxisScale::Linear => "linear".into_value(span),
+            AxisScale::Log10 => "log10".into_value(span),
+            AxisScale::Ln => "ln".into_value(span),
+            AxisScale::SymLog { linthresh } => {
+                let mut record = Record::new();
+                record.push("type", "symlog".into_value(span));
+                record.push("linthresh", linthresh.into_value(span));
+                Value::record(record, span)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for AxisScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AxisScale::Linear => write!(f, "linear"),
+            AxisScale::Log10 => write!(f, "log10"),
+            AxisScale::Ln => write!(f, "ln"),
+            AxisScale::SymLog { .. } => write!(f, "symlog"),
+        }
+    }
+}
+
+/// Resolve a scale by its canonical name, used by both the string and record
+/// forms. `symlog` resolves to its default threshold here.
+fn scale_from_name(name: &str, span: Span) -> Result<AxisScale, ShellError> {
+    match name {
+        "linear" => Ok(AxisScale::Linear),
+        "log10" => Ok(AxisScale::Log10),
+        "ln" => Ok(AxisScale::Ln),
+        "symlog" => Ok(AxisScale::SymLog { linthresh: 1.0 }),
+        _ => Err(ShellError::CantConvert {
+            to_type: AxisScale::expected_type().to_string(),
+            from_type: Type::String.to_string(),
+            span,
+            help: Some("Expected one of: linear, log10, ln, symlog.".into()),
+        }),
+    }
+}
+
+impl FromValue for AxisScale {
+    fn from_value(v: Value) -> Result<Self, ShellError> {
+        match v {
+            Value::String { val, internal_span } => scale_from_name(&val, internal_span),
+
+            v @ Value::Record { .. } => {
+                #[derive(Debug, FromValue)]
+                struct AxisScaleDTO {
+                    r#type: String,
+                    linthresh: Option<f64>,
+                }
+
+                let span = v.span();
+                let AxisScaleDTO { r#type, linthresh } = AxisScaleDTO::from_value(v)?;
+                match r#type.as_str() {
+                    // `linthresh` only applies to the symlog scale; it is
+                    // accepted (and ignored) elsewhere for convenience.
+                    "symlog" => Ok(AxisScale::SymLog {
+                        linthresh: linthresh.unwrap_or(1.0),
+                    }),
+                    _ => scale_from_name(&r#type, span),
+                }
+            },
+
+            v => Err(ShellError::CantConvert {
+                to_type: Self::expected_type().to_string(),
+                from_type: v.get_type().to_string(),
+                span: v.span(),
+                help: None,
+            }),
+        }
+    }
+
+    fn expected_type() -> Type {
+        Type::String
+    }
+}
+
+/// A possibly open-ended interval. A `None` bound is resolved from the
+/// plotted series data (and then padded) while computing a chart's extents.
 #[derive(Debug, Clone, Copy, IntoValue, Serialize, Deserialize)]
 pub struct Range {
-    pub min: f64,
-    pub max: f64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
 }
 
 impl FromValue for Range {
     fn from_value(v: Value) -> Result<Self, ShellError> {
         match v {
-            Value::Range { val, internal_span } => {
+            Value::Range { val, .. } => {
                 let range = FloatRange::from(*val);
-                let min = range.start();
+                let min = Some(range.start());
                 let max = match range.end() {
-                    Bound::Included(max) => max,
-                    Bound::Excluded(max) => max,
-                    Bound::Unbounded => return Err(ShellError::CantConvert { 
-                        to_type: Self::expected_type().to_string(), 
-                        from_type: Type::Range.to_string(), 
-                        span: internal_span, 
-                        help: Some("Try a bounded range instead.".to_string())
-                    }),
+                    Bound::Included(max) => Some(max),
+                    Bound::Excluded(max) => Some(max),
+                    // An unbounded end is resolved from the series data.
+                    Bound::Unbounded => None,
                 };
 
                 Ok(Self { min, max })
@@ -142,20 +368,20 @@ impl FromValue for Range {
 
             v @ Value::List { .. } => {
                 let [min, max] = <[f64; 2]>::from_value(v)?;
-                Ok(Self { min, max })
+                Ok(Self { min: Some(min), max: Some(max) })
             },
 
             v @ Value::Record { .. } => {
                 #[derive(Debug, FromValue)]
                 struct RangeDTO {
-                    min: f64,
-                    max: f64,
+                    min: Option<f64>,
+                    max: Option<f64>,
                 }
 
                 let RangeDTO { min, max } = RangeDTO::from_value(v)?;
-                Ok(Self { min, max }) 
+                Ok(Self { min, max })
             },
-            
+
             v => Err(ShellError::CantConvert {
                 to_type: Self::expected_type().to_string(),
                 from_type: v.get_type().to_string(),
@@ -169,3 +395,160 @@ impl FromValue for Range {
         Type::List(Box::new(Type::Number))
     }
 }
+
+/// Where the legend box is anchored within (or beside) the drawing area.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum LegendPosition {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    /// Placed outside the plotting area, to the right of the chart.
+    Outside,
+}
+
+impl IntoValue for LegendPosition {
+    // Emit the same kebab-case names `FromValue` accepts so a legend config
+    // round-trips through `Value` instead of producing `TopLeft` etc.
+    fn into_value(self, span: Span) -> Value {
+        let name = match self {
+            LegendPosition::TopLeft => "top-left",
+            LegendPosition::TopRight => "top-right",
+            LegendPosition::BottomLeft => "bottom-left",
+            LegendPosition::BottomRight => "bottom-right",
+            LegendPosition::Outside => "outside",
+        };
+        name.into_value(span)
+    }
+}
+
+impl FromValue for LegendPosition {
+    fn from_value(v: Value) -> Result<Self, ShellError> {
+        match v {
+            Value::String { val, internal_span } => match val.as_str() {
+                "top-left" => Ok(LegendPosition::TopLeft),
+                "top-right" => Ok(LegendPosition::TopRight),
+                "bottom-left" => Ok(LegendPosition::BottomLeft),
+                "bottom-right" => Ok(LegendPosition::BottomRight),
+                "outside" => Ok(LegendPosition::Outside),
+                _ => Err(ShellError::CantConvert {
+                    to_type: Self::expected_type().to_string(),
+                    from_type: Type::String.to_string(),
+                    span: internal_span,
+                    help: Some(
+                        "Expected one of: top-left, top-right, bottom-left, bottom-right, outside."
+                            .into(),
+                    ),
+                }),
+            },
+
+            v => Err(ShellError::CantConvert {
+                to_type: Self::expected_type().to_string(),
+                from_type: v.get_type().to_string(),
+                span: v.span(),
+                help: None,
+            }),
+        }
+    }
+
+    fn expected_type() -> Type {
+        Type::String
+    }
+}
+
+/// Controls the legend that labels each [`Series2d`] in the rendered chart.
+#[derive(Debug, Clone, IntoValue, Serialize, Deserialize)]
+pub struct LegendConfig {
+    pub position: LegendPosition,
+    pub background: Option<Color>,
+    pub border: bool,
+}
+
+impl Default for LegendConfig {
+    fn default() -> Self {
+        Self {
+            position: LegendPosition::default(),
+            background: None,
+            border: true,
+        }
+    }
+}
+
+impl FromValue for LegendConfig {
+    fn from_value(v: Value) -> Result<Self, ShellError> {
+        match v {
+            v @ Value::Record { .. } => {
+                #[derive(Debug, FromValue)]
+                struct LegendConfigDTO {
+                    position: Option<LegendPosition>,
+                    background: Option<Color>,
+                    border: Option<bool>,
+                }
+
+                let LegendConfigDTO { position, background, border } =
+                    LegendConfigDTO::from_value(v)?;
+                let default = LegendConfig::default();
+                Ok(Self {
+                    position: position.unwrap_or(default.position),
+                    background,
+                    border: border.unwrap_or(default.border),
+                })
+            },
+
+            v => Err(ShellError::CantConvert {
+                to_type: Self::expected_type().to_string(),
+                from_type: v.get_type().to_string(),
+                span: v.span(),
+                help: None,
+            }),
+        }
+    }
+
+    fn expected_type() -> Type {
+        Type::Custom("plotters::legend-config".to_string().into_boxed_str())
+    }
+}
+
+/// How axis tick labels are produced: a fixed number of ticks, a format
+/// string applied to each tick value, or a Nushell closure invoked per tick.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TickFormat {
+    /// Render roughly this many evenly spaced ticks.
+    Count(usize),
+    /// A format string applied to each tick value (e.g. `"${}"`).
+    Format(String),
+    /// A closure `{|v| ... }` mapping a tick value to its label.
+    Closure(Closure),
+}
+
+impl IntoValue for TickFormat {
+    fn into_value(self, span: Span) -> Value {
+        match self {
+            TickFormat::Count(count) => (count as i64).into_value(span),
+            TickFormat::Format(format) => format.into_value(span),
+            TickFormat::Closure(closure) => Value::closure(closure, span),
+        }
+    }
+}
+
+impl FromValue for TickFormat {
+    fn from_value(v: Value) -> Result<Self, ShellError> {
+        match v {
+            Value::Int { val, .. } => Ok(TickFormat::Count(val.max(0) as usize)),
+            Value::String { val, .. } => Ok(TickFormat::Format(val)),
+            Value::Closure { val, .. } => Ok(TickFormat::Closure(*val)),
+
+            v => Err(ShellError::CantConvert {
+                to_type: Self::expected_type().to_string(),
+                from_type: v.get_type().to_string(),
+                span: v.span(),
+                help: Some("Expected a tick count, a format string, or a closure.".into()),
+            }),
+        }
+    }
+
+    fn expected_type() -> Type {
+        Type::Any
+    }
+}